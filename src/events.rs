@@ -0,0 +1,112 @@
+use bevy_ecs::prelude::*;
+
+#[cfg(feature = "color-scheme")]
+use crate::ColorScheme;
+#[cfg(feature = "contrast")]
+use crate::Contrast;
+#[cfg(feature = "reduced-motion")]
+use crate::ReducedMotion;
+#[cfg(feature = "reduced-transparency")]
+use crate::ReducedTransparency;
+#[cfg(feature = "accent-color")]
+use crate::AccentColor;
+
+/// Fired when [`Preferences::color_scheme`](crate::Preferences::color_scheme) changes.
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+#[cfg(feature = "color-scheme")]
+pub struct ColorSchemeChanged {
+    /// The previous value.
+    pub old: ColorScheme,
+    /// The new value.
+    pub new: ColorScheme,
+}
+
+/// Fired when [`Preferences::contrast`](crate::Preferences::contrast) changes.
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+#[cfg(feature = "contrast")]
+pub struct ContrastChanged {
+    /// The previous value.
+    pub old: Contrast,
+    /// The new value.
+    pub new: Contrast,
+}
+
+/// Fired when [`Preferences::reduced_motion`](crate::Preferences::reduced_motion) changes.
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+#[cfg(feature = "reduced-motion")]
+pub struct ReducedMotionChanged {
+    /// The previous value.
+    pub old: ReducedMotion,
+    /// The new value.
+    pub new: ReducedMotion,
+}
+
+/// Fired when [`Preferences::reduced_transparency`](crate::Preferences::reduced_transparency) changes.
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+#[cfg(feature = "reduced-transparency")]
+pub struct ReducedTransparencyChanged {
+    /// The previous value.
+    pub old: ReducedTransparency,
+    /// The new value.
+    pub new: ReducedTransparency,
+}
+
+/// Fired when [`Preferences::accent_color`](crate::Preferences::accent_color) changes.
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+#[cfg(feature = "accent-color")]
+pub struct AccentColorChanged {
+    /// The previous value.
+    pub old: AccentColor,
+    /// The new value.
+    pub new: AccentColor,
+}
+
+/// Registers the change events fired by [`crate::MundyPlugin`].
+pub(crate) fn register_events(app: &mut bevy_app::App) {
+    #[cfg(feature = "color-scheme")]
+    app.add_event::<ColorSchemeChanged>();
+    #[cfg(feature = "contrast")]
+    app.add_event::<ContrastChanged>();
+    #[cfg(feature = "reduced-motion")]
+    app.add_event::<ReducedMotionChanged>();
+    #[cfg(feature = "reduced-transparency")]
+    app.add_event::<ReducedTransparencyChanged>();
+    #[cfg(feature = "accent-color")]
+    app.add_event::<AccentColorChanged>();
+}
+
+/// Run condition that is true for any frame in which [`ColorSchemeChanged`] was fired.
+///
+/// ```ignore
+/// app.add_systems(Update, rebuild_theme.run_if(on_color_scheme_changed()));
+/// ```
+#[cfg(feature = "color-scheme")]
+pub fn on_color_scheme_changed() -> impl FnMut(EventReader<ColorSchemeChanged>) -> bool + Clone {
+    bevy_ecs::event::on_event::<ColorSchemeChanged>()
+}
+
+/// Run condition that is true for any frame in which [`ContrastChanged`] was fired.
+#[cfg(feature = "contrast")]
+pub fn on_contrast_changed() -> impl FnMut(EventReader<ContrastChanged>) -> bool + Clone {
+    bevy_ecs::event::on_event::<ContrastChanged>()
+}
+
+/// Run condition that is true for any frame in which [`ReducedMotionChanged`] was fired.
+#[cfg(feature = "reduced-motion")]
+pub fn on_reduced_motion_changed()
+-> impl FnMut(EventReader<ReducedMotionChanged>) -> bool + Clone {
+    bevy_ecs::event::on_event::<ReducedMotionChanged>()
+}
+
+/// Run condition that is true for any frame in which [`ReducedTransparencyChanged`] was fired.
+#[cfg(feature = "reduced-transparency")]
+pub fn on_reduced_transparency_changed()
+-> impl FnMut(EventReader<ReducedTransparencyChanged>) -> bool + Clone {
+    bevy_ecs::event::on_event::<ReducedTransparencyChanged>()
+}
+
+/// Run condition that is true for any frame in which [`AccentColorChanged`] was fired.
+#[cfg(feature = "accent-color")]
+pub fn on_accent_color_changed() -> impl FnMut(EventReader<AccentColorChanged>) -> bool + Clone {
+    bevy_ecs::event::on_event::<AccentColorChanged>()
+}
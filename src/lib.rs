@@ -5,24 +5,112 @@ use bevy_tasks::futures_lite::StreamExt;
 use mundy::Interest;
 pub use mundy::{ColorScheme, Contrast, DoubleClickInterval, ReducedMotion, ReducedTransparency};
 
+mod events;
+pub use events::*;
+
 mod preferences;
 pub use preferences::*;
 
+#[cfg(feature = "accent-color")]
+mod palette;
+#[cfg(feature = "accent-color")]
+pub use palette::*;
+
+#[cfg(feature = "multi-click")]
+mod multi_click;
+#[cfg(feature = "multi-click")]
+pub use multi_click::{MultiClick, MultiClickConfig};
+
+#[cfg(feature = "bevy_window")]
+mod window_appearance;
+#[cfg(feature = "bevy_window")]
+pub use window_appearance::WindowAppearance;
+
+mod overrides;
+pub use overrides::*;
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, SystemSet)]
 pub struct MundySystems;
 
 #[derive(Debug, Default)]
 #[non_exhaustive]
-pub struct MundyPlugin {}
+pub struct MundyPlugin {
+    overrides: OverrideSource,
+}
+
+impl MundyPlugin {
+    /// Loads [`PreferenceOverrides`] from `source` before the first poll of the live
+    /// OS preferences, so overridden fields are masked from the very first frame.
+    ///
+    /// If `source` is [`OverrideSource::None`], a [`PreferenceOverrides`] resource
+    /// inserted before this plugin is added is left as-is (and otherwise defaults to
+    /// no overrides at all).
+    pub fn with_overrides(mut self, source: OverrideSource) -> Self {
+        self.overrides = source;
+        self
+    }
+}
 
 impl Plugin for MundyPlugin {
     fn build(&self, app: &mut App) {
+        // Resolve the overrides up front so they can mask the very first
+        // `Preferences` value, not just every value after the first stream tick —
+        // the live `mundy::Preferences::stream` is unbounded and may never tick at
+        // all on platforms/tests that don't support a given preference.
+        let overrides = match &self.overrides {
+            OverrideSource::None => app
+                .world()
+                .get_resource::<PreferenceOverrides>()
+                .copied()
+                .unwrap_or_default(),
+            source => source.load(),
+        };
+        let mut initial_preferences = Preferences::default();
+        overrides.apply(&mut initial_preferences);
+
         app.register_type::<Preferences>()
             .configure_sets(Startup, MundySystems)
             .configure_sets(PreUpdate, MundySystems)
-            .init_resource::<Preferences>()
+            .insert_resource(initial_preferences)
             .add_systems(Startup, subscribe_to_preferencs.in_set(MundySystems))
             .add_systems(PreUpdate, poll_system_preferences.in_set(MundySystems));
+        register_events(app);
+
+        app.register_type::<PreferenceOverrides>()
+            .insert_resource(overrides);
+
+        #[cfg(feature = "accent-color")]
+        app.register_type::<SystemPalette>()
+            .init_resource::<SystemPalette>()
+            .add_systems(
+                PreUpdate,
+                palette::update_system_palette
+                    .in_set(MundySystems)
+                    .after(poll_system_preferences),
+            );
+
+        #[cfg(feature = "multi-click")]
+        app.add_event::<MultiClick>()
+            .register_type::<MultiClickConfig>()
+            .init_resource::<MultiClickConfig>()
+            .init_resource::<multi_click::MultiClickState>()
+            .add_systems(
+                PreUpdate,
+                multi_click::sync_multi_click_config
+                    .in_set(MundySystems)
+                    .after(poll_system_preferences),
+            )
+            .add_systems(Update, multi_click::detect_multi_clicks);
+
+        #[cfg(feature = "bevy_window")]
+        {
+            // Seed from any `ClearColor` the app already set, so it's never
+            // clobbered by this system's first run.
+            let managed_clear_color = window_appearance::ManagedClearColor::seeded_from(app.world());
+            app.register_type::<WindowAppearance>()
+                .insert_resource(managed_clear_color)
+                .add_systems(Update, window_appearance::apply_window_appearance);
+        }
     }
 }
 
@@ -49,13 +137,95 @@ struct Receiver(crossbeam_channel::Receiver<mundy::Preferences>);
 
 fn poll_system_preferences(
     receiver: Res<Receiver>,
+    overrides: Res<PreferenceOverrides>,
     mut preferences_res: ResMut<Preferences>,
+    #[cfg(feature = "color-scheme")] mut color_scheme_events: EventWriter<ColorSchemeChanged>,
+    #[cfg(feature = "contrast")] mut contrast_events: EventWriter<ContrastChanged>,
+    #[cfg(feature = "reduced-motion")] mut reduced_motion_events: EventWriter<
+        ReducedMotionChanged,
+    >,
+    #[cfg(feature = "reduced-transparency")] mut reduced_transparency_events: EventWriter<
+        ReducedTransparencyChanged,
+    >,
+    #[cfg(feature = "accent-color")] mut accent_color_events: EventWriter<AccentColorChanged>,
 ) -> Result {
     let preferences = match receiver.0.try_recv() {
         Ok(preferences) => preferences,
         Err(crossbeam_channel::TryRecvError::Empty) => return Ok(()),
         Err(e) => return Err(e.into()),
     };
-    *preferences_res = preferences.into();
+    let old = *preferences_res;
+    let mut new: Preferences = preferences.into();
+    overrides.apply(&mut new);
+
+    #[cfg(feature = "color-scheme")]
+    if old.color_scheme != new.color_scheme {
+        color_scheme_events.write(ColorSchemeChanged {
+            old: old.color_scheme,
+            new: new.color_scheme,
+        });
+    }
+    #[cfg(feature = "contrast")]
+    if old.contrast != new.contrast {
+        contrast_events.write(ContrastChanged {
+            old: old.contrast,
+            new: new.contrast,
+        });
+    }
+    #[cfg(feature = "reduced-motion")]
+    if old.reduced_motion != new.reduced_motion {
+        reduced_motion_events.write(ReducedMotionChanged {
+            old: old.reduced_motion,
+            new: new.reduced_motion,
+        });
+    }
+    #[cfg(feature = "reduced-transparency")]
+    if old.reduced_transparency != new.reduced_transparency {
+        reduced_transparency_events.write(ReducedTransparencyChanged {
+            old: old.reduced_transparency,
+            new: new.reduced_transparency,
+        });
+    }
+    #[cfg(feature = "accent-color")]
+    if old.accent_color != new.accent_color {
+        accent_color_events.write(AccentColorChanged {
+            old: old.accent_color,
+            new: new.accent_color,
+        });
+    }
+
+    *preferences_res = new;
     Ok(())
 }
+
+#[cfg(test)]
+#[cfg(feature = "color-scheme")]
+mod tests {
+    use super::*;
+
+    /// The live OS preferences stream is spawned on the `IoTaskPool` and may never
+    /// tick within a test (or on a platform that doesn't support a given
+    /// preference), so overrides must already be visible right after the plugin is
+    /// built — before `poll_system_preferences` has ever run.
+    #[test]
+    fn overrides_apply_before_the_stream_ever_ticks() {
+        let mut app = App::new();
+        app.insert_resource(PreferenceOverrides {
+            color_scheme: Some(ColorScheme::Dark),
+            ..Default::default()
+        });
+        app.add_plugins(MundyPlugin::default());
+
+        let preferences = app.world().resource::<Preferences>();
+        assert_eq!(preferences.color_scheme, ColorScheme::Dark);
+    }
+
+    #[test]
+    fn with_overrides_none_keeps_no_overrides_by_default() {
+        let mut app = App::new();
+        app.add_plugins(MundyPlugin::default().with_overrides(OverrideSource::None));
+
+        let preferences = app.world().resource::<Preferences>();
+        assert_eq!(preferences.color_scheme, ColorScheme::NoPreference);
+    }
+}
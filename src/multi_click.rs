@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use bevy_ecs::prelude::*;
+use bevy_input::ButtonInput;
+use bevy_input::mouse::MouseButton;
+use bevy_math::Vec2;
+use bevy_reflect::prelude::*;
+use bevy_window::{PrimaryWindow, Window};
+
+use crate::Preferences;
+
+/// Configuration for detecting double/triple (and beyond) click sequences, built on
+/// top of the OS-reported [`crate::DoubleClickInterval`].
+///
+/// `time` is kept in sync with [`Preferences::double_click_interval`] by
+/// [`crate::MundyPlugin`]; `area` has no OS equivalent, so it is left for the app to
+/// tune and defaults to a small ~4px box.
+#[derive(Debug, Clone, Copy, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct MultiClickConfig {
+    /// The maximum amount of time that may occur between two consecutive clicks for
+    /// them to belong to the same click sequence.
+    pub time: Duration,
+    /// The maximum distance the pointer may travel, relative to the *first* click in
+    /// the sequence, for a later click to still belong to that sequence.
+    pub area: Vec2,
+}
+
+impl Default for MultiClickConfig {
+    fn default() -> Self {
+        MultiClickConfig {
+            time: Duration::from_millis(500),
+            area: Vec2::splat(4.0),
+        }
+    }
+}
+
+/// Fired for every click, with `count` the number of consecutive clicks of the same
+/// mouse button that landed within [`MultiClickConfig::time`] and
+/// [`MultiClickConfig::area`] of each other (`1` for a click that starts a sequence).
+///
+/// `count` saturates at `3` so consumers can treat anything beyond a triple click as
+/// "triple click and keep going" without having to match on arbitrarily large numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct MultiClick {
+    /// The button that was clicked.
+    pub button: MouseButton,
+    /// The number of clicks in the current sequence, saturating at `3`.
+    pub count: u8,
+}
+
+/// The in-progress click sequence for a single mouse button.
+struct ClickSequence {
+    /// Where the sequence started.
+    first_click_pos: Vec2,
+    /// When the most recent click in the sequence landed.
+    last_click_at: Instant,
+    /// The number of clicks seen so far.
+    count: u8,
+}
+
+/// Tracks the in-progress [`ClickSequence`] per mouse button.
+#[derive(Default, Resource)]
+pub(crate) struct MultiClickState {
+    sequences: HashMap<MouseButton, ClickSequence>,
+}
+
+/// Keeps [`MultiClickConfig::time`] in sync with the live OS double-click interval.
+///
+/// When the `double-click-interval` feature is disabled, [`Preferences`] doesn't carry
+/// that field at all, so [`MultiClickConfig::time`] just keeps whatever value it was
+/// constructed or configured with.
+pub(crate) fn sync_multi_click_config(
+    preferences: Res<Preferences>,
+    mut config: ResMut<MultiClickConfig>,
+) {
+    if preferences.is_changed() {
+        if let Some(time) = double_click_interval(&preferences) {
+            config.time = time;
+        }
+    }
+}
+
+#[cfg(feature = "double-click-interval")]
+fn double_click_interval(preferences: &Preferences) -> Option<Duration> {
+    preferences.double_click_interval.0
+}
+
+#[cfg(not(feature = "double-click-interval"))]
+fn double_click_interval(_preferences: &Preferences) -> Option<Duration> {
+    None
+}
+
+/// Walks this frame's newly pressed mouse buttons, extends or restarts the matching
+/// [`ClickSequence`], and fires [`MultiClick`] for every click. Clicks are ignored
+/// while the primary window has no cursor position.
+pub(crate) fn detect_multi_clicks(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    config: Res<MultiClickConfig>,
+    mut state: ResMut<MultiClickState>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut events: EventWriter<MultiClick>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Some(cursor_pos) = window.cursor_position() else {
+        return;
+    };
+    let now = Instant::now();
+
+    for &button in mouse_buttons.get_just_pressed() {
+        let count = match state.sequences.get(&button) {
+            Some(sequence)
+                if now.duration_since(sequence.last_click_at) <= config.time
+                    && within_area(cursor_pos, sequence.first_click_pos, config.area) =>
+            {
+                sequence.count.saturating_add(1)
+            }
+            _ => 1,
+        };
+
+        let first_click_pos = match state.sequences.get(&button) {
+            Some(sequence) if count > 1 => sequence.first_click_pos,
+            _ => cursor_pos,
+        };
+
+        state.sequences.insert(
+            button,
+            ClickSequence {
+                first_click_pos,
+                last_click_at: now,
+                count,
+            },
+        );
+
+        events.write(MultiClick {
+            button,
+            count: count.min(3),
+        });
+    }
+}
+
+fn within_area(pos: Vec2, origin: Vec2, area: Vec2) -> bool {
+    (pos.x - origin.x).abs() <= area.x && (pos.y - origin.y).abs() <= area.y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn within_area_accepts_points_inside_the_box() {
+        let area = Vec2::splat(4.0);
+        assert!(within_area(Vec2::new(2.0, -3.0), Vec2::ZERO, area));
+        assert!(within_area(Vec2::new(4.0, 4.0), Vec2::ZERO, area));
+    }
+
+    #[test]
+    fn within_area_rejects_points_outside_the_box() {
+        let area = Vec2::splat(4.0);
+        assert!(!within_area(Vec2::new(4.1, 0.0), Vec2::ZERO, area));
+        assert!(!within_area(Vec2::new(0.0, -10.0), Vec2::ZERO, area));
+    }
+
+    /// Mirrors the sequence-extend-or-reset decision made in [`detect_multi_clicks`],
+    /// without needing a full `App`/window/input harness.
+    fn next_count(
+        previous: Option<&ClickSequence>,
+        now: Instant,
+        pos: Vec2,
+        config: &MultiClickConfig,
+    ) -> u8 {
+        match previous {
+            Some(sequence)
+                if now.duration_since(sequence.last_click_at) <= config.time
+                    && within_area(pos, sequence.first_click_pos, config.area) =>
+            {
+                sequence.count.saturating_add(1)
+            }
+            _ => 1,
+        }
+    }
+
+    #[test]
+    fn sequence_extends_within_time_and_area() {
+        let config = MultiClickConfig::default();
+        let first_click_at = Instant::now();
+        let sequence = ClickSequence {
+            first_click_pos: Vec2::ZERO,
+            last_click_at: first_click_at,
+            count: 1,
+        };
+
+        let count = next_count(Some(&sequence), first_click_at, Vec2::new(1.0, 1.0), &config);
+
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn sequence_resets_when_click_lands_outside_the_area() {
+        let config = MultiClickConfig::default();
+        let now = Instant::now();
+        let sequence = ClickSequence {
+            first_click_pos: Vec2::ZERO,
+            last_click_at: now,
+            count: 2,
+        };
+
+        let count = next_count(Some(&sequence), now, config.area * 10.0, &config);
+
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn sequence_resets_when_click_lands_too_late() {
+        let config = MultiClickConfig::default();
+        let now = Instant::now();
+        let sequence = ClickSequence {
+            first_click_pos: Vec2::ZERO,
+            last_click_at: now - config.time - Duration::from_millis(1),
+            count: 2,
+        };
+
+        let count = next_count(Some(&sequence), now, Vec2::ZERO, &config);
+
+        assert_eq!(count, 1);
+    }
+}
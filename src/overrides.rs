@@ -0,0 +1,126 @@
+use std::path::PathBuf;
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "accent-color")]
+use crate::AccentColor;
+#[cfg(feature = "contrast")]
+use crate::Contrast;
+#[cfg(feature = "double-click-interval")]
+use crate::DoubleClickInterval;
+#[cfg(feature = "reduced-motion")]
+use crate::ReducedMotion;
+#[cfg(feature = "reduced-transparency")]
+use crate::ReducedTransparency;
+#[cfg(feature = "color-scheme")]
+use crate::ColorScheme;
+use crate::Preferences;
+
+/// Where [`MundyPlugin`](crate::MundyPlugin) should load a [`PreferenceOverrides`]
+/// from before the plugin starts polling the live OS preferences.
+///
+/// This exists for designers who want to preview "what does dark + high-contrast look
+/// like" without touching OS settings, and for tests/examples/platforms where a
+/// preference is unsupported and the live [`mundy::Preferences::stream`] can't be
+/// exercised at all.
+#[derive(Debug, Clone, Default)]
+pub enum OverrideSource {
+    /// Don't load overrides from a file; rely solely on a [`PreferenceOverrides`]
+    /// resource inserted before [`MundyPlugin`](crate::MundyPlugin) is added (or none
+    /// at all).
+    #[default]
+    None,
+    /// Load a serialized [`PreferenceOverrides`] from a RON file, like pywal's
+    /// `colors.json`.
+    #[cfg(feature = "serialize")]
+    RonFile(PathBuf),
+    /// Load a serialized [`PreferenceOverrides`] from a JSON file, like pywal's
+    /// `colors.json`.
+    #[cfg(feature = "serialize")]
+    JsonFile(PathBuf),
+}
+
+impl OverrideSource {
+    /// Loads the [`PreferenceOverrides`] described by this source, falling back to
+    /// [`PreferenceOverrides::default`] (no overrides) if there is nothing to load, or
+    /// if loading fails.
+    pub(crate) fn load(&self) -> PreferenceOverrides {
+        match self {
+            OverrideSource::None => PreferenceOverrides::default(),
+            #[cfg(feature = "serialize")]
+            OverrideSource::RonFile(path) => std::fs::read_to_string(path)
+                .ok()
+                .and_then(|contents| ron::from_str(&contents).ok())
+                .unwrap_or_default(),
+            #[cfg(feature = "serialize")]
+            OverrideSource::JsonFile(path) => std::fs::read_to_string(path)
+                .ok()
+                .and_then(|contents| serde_json::from_str(&contents).ok())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// A set of `Some`-masked [`Preferences`] fields that, when present, take priority
+/// over whatever the OS reports.
+///
+/// Fields left `None` are passed through untouched, so a partial override (e.g. just
+/// forcing [`ColorScheme::Dark`]) still lets the real OS drive everything else.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Resource, Reflect)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[reflect(Resource)]
+#[non_exhaustive]
+pub struct PreferenceOverrides {
+    /// Overrides [`Preferences::color_scheme`].
+    #[cfg(feature = "color-scheme")]
+    pub color_scheme: Option<ColorScheme>,
+    /// Overrides [`Preferences::contrast`].
+    #[cfg(feature = "contrast")]
+    pub contrast: Option<Contrast>,
+    /// Overrides [`Preferences::reduced_motion`].
+    #[cfg(feature = "reduced-motion")]
+    pub reduced_motion: Option<ReducedMotion>,
+    /// Overrides [`Preferences::reduced_transparency`].
+    #[cfg(feature = "reduced-transparency")]
+    pub reduced_transparency: Option<ReducedTransparency>,
+    /// Overrides [`Preferences::accent_color`].
+    #[cfg(feature = "accent-color")]
+    pub accent_color: Option<AccentColor>,
+    /// Overrides [`Preferences::double_click_interval`].
+    #[cfg(feature = "double-click-interval")]
+    pub double_click_interval: Option<DoubleClickInterval>,
+}
+
+impl PreferenceOverrides {
+    /// Masks every field of `preferences` that this override set has an opinion on,
+    /// leaving the rest fed by the live OS stream.
+    pub(crate) fn apply(&self, preferences: &mut Preferences) {
+        #[cfg(feature = "color-scheme")]
+        if let Some(color_scheme) = self.color_scheme {
+            preferences.color_scheme = color_scheme;
+        }
+        #[cfg(feature = "contrast")]
+        if let Some(contrast) = self.contrast {
+            preferences.contrast = contrast;
+        }
+        #[cfg(feature = "reduced-motion")]
+        if let Some(reduced_motion) = self.reduced_motion {
+            preferences.reduced_motion = reduced_motion;
+        }
+        #[cfg(feature = "reduced-transparency")]
+        if let Some(reduced_transparency) = self.reduced_transparency {
+            preferences.reduced_transparency = reduced_transparency;
+        }
+        #[cfg(feature = "accent-color")]
+        if let Some(accent_color) = self.accent_color {
+            preferences.accent_color = accent_color;
+        }
+        #[cfg(feature = "double-click-interval")]
+        if let Some(double_click_interval) = self.double_click_interval {
+            preferences.double_click_interval = double_click_interval;
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use bevy_color::{Color, Oklcha};
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+
+#[cfg(feature = "color-scheme")]
+use crate::ColorScheme;
+#[cfg(feature = "contrast")]
+use crate::Contrast;
+use crate::Preferences;
+
+/// The minimum WCAG contrast ratio `(L1+0.05)/(L2+0.05)` required between a
+/// [`SystemPalette::on_surface`] and [`SystemPalette::surface`] pair.
+const MIN_CONTRAST_RATIO: f32 = 4.5;
+
+/// A number of evenly spaced lightness stops generated around the accent color, like
+/// the palette a tool such as pywal derives from a single seed color.
+const RAMP_LEN: usize = 9;
+
+/// A small, drop-in UI color palette derived from the user's [`AccentColor`],
+/// [`ColorScheme`] and [`Contrast`] preferences.
+///
+/// This is updated alongside [`Preferences`] by [`crate::MundyPlugin`], so it always
+/// reflects the live OS theme without every consumer re-deriving colors from the raw
+/// accent color themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+#[cfg(feature = "accent-color")]
+pub struct SystemPalette {
+    /// The page/window background color.
+    pub surface: Color,
+    /// The default text/icon color drawn on top of [`Self::surface`].
+    pub on_surface: Color,
+    /// The accent color at its base lightness.
+    pub accent: Color,
+    /// A darker variant of [`Self::accent`], suitable for hover/pressed states.
+    pub accent_hover: Color,
+    /// A desaturated, low-emphasis variant of [`Self::accent`] for muted UI such as
+    /// disabled controls.
+    pub accent_muted: Color,
+    /// A 9-stop lightness ramp of the accent color, from lightest (`ramp[0]`) to
+    /// darkest (`ramp[8]`).
+    pub ramp: [Color; RAMP_LEN],
+}
+
+#[cfg(feature = "accent-color")]
+impl Default for SystemPalette {
+    fn default() -> Self {
+        SystemPalette::from_preferences(&Preferences::default())
+    }
+}
+
+#[cfg(feature = "accent-color")]
+impl SystemPalette {
+    /// Derives a full palette from the given [`Preferences`].
+    pub fn from_preferences(preferences: &Preferences) -> Self {
+        let seed = match preferences.accent_color.0 {
+            Some(color) => Oklcha::from(color),
+            // Neutral gray seed when the platform reports no accent color.
+            None => Oklcha::new(0.5, 0.0, 0.0, 1.0),
+        };
+
+        let high_contrast = is_high_contrast(preferences);
+        let (mut lightest, mut darkest) = (0.95, 0.2);
+        if high_contrast {
+            lightest = 0.98;
+            darkest = 0.08;
+        }
+
+        let ramp = std::array::from_fn(|i| {
+            let t = i as f32 / (RAMP_LEN - 1) as f32;
+            let lightness = lightest + (darkest - lightest) * t;
+            Color::from(Oklcha {
+                lightness,
+                ..seed
+            })
+        });
+
+        let dark_mode = is_dark_mode(preferences);
+        let mut surface = Oklcha::new(if dark_mode { 0.15 } else { 0.98 }, 0.0, 0.0, 1.0);
+        let mut on_surface = Oklcha::new(if dark_mode { 0.95 } else { 0.12 }, 0.0, 0.0, 1.0);
+        if high_contrast {
+            surface.lightness = if dark_mode { 0.08 } else { 1.0 };
+            on_surface.lightness = if dark_mode { 1.0 } else { 0.0 };
+        }
+        ensure_contrast(&mut on_surface, surface);
+
+        let accent = Oklcha {
+            lightness: if dark_mode { 0.7 } else { 0.55 },
+            ..seed
+        };
+        let accent_hover = Oklcha {
+            lightness: (accent.lightness - 0.1).max(darkest),
+            ..accent
+        };
+        let accent_muted = Oklcha {
+            chroma: seed.chroma * 0.35,
+            ..accent
+        };
+
+        SystemPalette {
+            surface: Color::from(surface),
+            on_surface: Color::from(on_surface),
+            accent: Color::from(accent),
+            accent_hover: Color::from(accent_hover),
+            accent_muted: Color::from(accent_muted),
+            ramp,
+        }
+    }
+}
+
+/// Whether [`Preferences::contrast`](crate::Preferences::contrast) is set to
+/// [`Contrast::More`]. Falls back to `false` when the `contrast` feature is disabled,
+/// since [`SystemPalette`] only depends on `accent-color`.
+#[cfg(feature = "accent-color")]
+fn is_high_contrast(preferences: &Preferences) -> bool {
+    #[cfg(feature = "contrast")]
+    {
+        preferences.contrast == Contrast::More
+    }
+    #[cfg(not(feature = "contrast"))]
+    {
+        let _ = preferences;
+        false
+    }
+}
+
+/// Whether [`Preferences::color_scheme`](crate::Preferences::color_scheme) is set to
+/// [`ColorScheme::Dark`]. Falls back to `false` when the `color-scheme` feature is
+/// disabled, since [`SystemPalette`] only depends on `accent-color`.
+#[cfg(feature = "accent-color")]
+fn is_dark_mode(preferences: &Preferences) -> bool {
+    #[cfg(feature = "color-scheme")]
+    {
+        preferences.color_scheme == ColorScheme::Dark
+    }
+    #[cfg(not(feature = "color-scheme"))]
+    {
+        let _ = preferences;
+        false
+    }
+}
+
+/// Nudges `foreground`'s lightness toward white or black, whichever gives more
+/// contrast against `background` (crossing `background` if need be), until the
+/// WCAG-style contrast ratio `(L1+0.05)/(L2+0.05)` clears [`MIN_CONTRAST_RATIO`].
+#[cfg(feature = "accent-color")]
+fn ensure_contrast(foreground: &mut Oklcha, background: Oklcha) {
+    const STEP: f32 = 0.02;
+
+    if contrast_ratio(foreground.lightness, background.lightness) >= MIN_CONTRAST_RATIO {
+        return;
+    }
+
+    // Pick whichever side of `background` can actually clear the threshold, rather
+    // than anchoring on whatever side `foreground` already happens to be on: e.g. with
+    // `background` at `0.5`, pushing a foreground of `0.52` up toward white caps out
+    // at a ~1.91:1 ratio, while pushing it down toward black reaches ~11:1.
+    let toward_white = contrast_ratio(1.0, background.lightness)
+        >= contrast_ratio(0.0, background.lightness);
+
+    while contrast_ratio(foreground.lightness, background.lightness) < MIN_CONTRAST_RATIO {
+        if toward_white {
+            if foreground.lightness + STEP > 1.0 {
+                foreground.lightness = 1.0;
+                break;
+            }
+            foreground.lightness += STEP;
+        } else {
+            if foreground.lightness - STEP < 0.0 {
+                foreground.lightness = 0.0;
+                break;
+            }
+            foreground.lightness -= STEP;
+        }
+    }
+}
+
+#[cfg(feature = "accent-color")]
+fn contrast_ratio(l_a: f32, l_b: f32) -> f32 {
+    let (lighter, darker) = if l_a >= l_b { (l_a, l_b) } else { (l_b, l_a) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+#[cfg(test)]
+#[cfg(feature = "accent-color")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ensure_contrast_reaches_threshold_and_terminates() {
+        let background = Oklcha::new(0.5, 0.1, 30.0, 1.0);
+        let mut foreground = Oklcha::new(0.52, 0.1, 30.0, 1.0);
+
+        ensure_contrast(&mut foreground, background);
+
+        assert!(contrast_ratio(foreground.lightness, background.lightness) >= MIN_CONTRAST_RATIO);
+    }
+
+    #[test]
+    fn ensure_contrast_picks_the_direction_with_headroom() {
+        // Pushing up from 0.5 toward white caps at a ~1.91:1 ratio; pushing down
+        // toward black reaches ~11:1, so this must go down, not up.
+        let background = Oklcha::new(0.5, 0.1, 30.0, 1.0);
+        let mut foreground = Oklcha::new(0.52, 0.1, 30.0, 1.0);
+
+        ensure_contrast(&mut foreground, background);
+
+        assert!(foreground.lightness < background.lightness);
+        assert!(contrast_ratio(foreground.lightness, background.lightness) >= MIN_CONTRAST_RATIO);
+    }
+
+    #[test]
+    fn ensure_contrast_is_noop_when_already_sufficient() {
+        let background = Oklcha::new(0.9, 0.1, 30.0, 1.0);
+        let mut foreground = Oklcha::new(0.1, 0.1, 30.0, 1.0);
+
+        ensure_contrast(&mut foreground, background);
+
+        assert_eq!(foreground.lightness, 0.1);
+    }
+
+    #[test]
+    fn contrast_ratio_is_symmetric() {
+        assert_eq!(contrast_ratio(0.9, 0.1), contrast_ratio(0.1, 0.9));
+    }
+}
+
+/// Recomputes [`SystemPalette`] whenever [`Preferences`] changes.
+#[cfg(feature = "accent-color")]
+pub(crate) fn update_system_palette(
+    preferences: Res<Preferences>,
+    mut palette: ResMut<SystemPalette>,
+) {
+    if preferences.is_changed() {
+        *palette = SystemPalette::from_preferences(&preferences);
+    }
+}
@@ -214,6 +214,9 @@ impl From<mundy::AccentColor> for AccentColor {
 ///
 /// A typical value for this setting is ~500 ms.
 ///
+/// This only carries the OS timing, not a spatial tolerance; see
+/// [`crate::MultiClickConfig`] for full double/triple-click sequence detection.
+///
 /// ## Sources
 /// * Linux (GNOME-only): `org.gnome.desktop.peripherals.mouse double-click` from the [XDG Settings portal][xdg].
 /// * Windows: [`GetDoubleClickTime`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdoubleclicktime)
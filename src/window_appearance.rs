@@ -0,0 +1,204 @@
+use bevy_color::Color;
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+use bevy_render::camera::ClearColor;
+use bevy_window::{CompositeAlphaMode, PrimaryWindow, Window};
+
+#[cfg(feature = "color-scheme")]
+use crate::ColorScheme;
+#[cfg(feature = "reduced-transparency")]
+use crate::ReducedTransparency;
+use crate::Preferences;
+
+/// The window compositing mode an app would like to use, analogous to Zed's
+/// `background.appearance` setting.
+///
+/// This is a request, not a guarantee: when [`ReducedTransparency::Reduce`] is set the
+/// window is always forced to [`WindowAppearance::Opaque`], regardless of this value.
+///
+/// Set this as a component on the primary window entity, or as a resource to apply it
+/// to the primary window when it has no such component. Only the primary window is
+/// configured. If neither is present, [`WindowAppearance::Opaque`] is assumed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Resource, Reflect)]
+#[reflect(Component, Resource)]
+pub enum WindowAppearance {
+    /// The window background is fully opaque.
+    #[default]
+    Opaque,
+    /// The window background shows through to whatever is behind it, using the
+    /// configured clear color's alpha.
+    Transparent,
+    /// Handled exactly like [`WindowAppearance::Transparent`]: no compositor blur is
+    /// requested for the window.
+    Blurred,
+}
+
+/// The default light and dark clear colors used when the app hasn't set a
+/// [`ClearColor`] of its own.
+const LIGHT_CLEAR_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const DARK_CLEAR_COLOR: Color = Color::srgb(0.1, 0.1, 0.1);
+
+/// Tracks the last [`ClearColor`] we set, so we can tell it apart from one the app set
+/// itself. Once the app is seen overriding it, we stop touching [`ClearColor`]
+/// entirely (aside from stripping its alpha while the window is opaque) rather than
+/// fighting the app frame after frame.
+#[derive(Debug, Default, Resource)]
+pub(crate) struct ManagedClearColor {
+    last_set: Option<Color>,
+    overridden_by_app: bool,
+}
+
+impl ManagedClearColor {
+    /// Seeds `last_set` from any [`ClearColor`] already present in `world`, so an app
+    /// that sets its own clear color before adding [`crate::MundyPlugin`] (the normal
+    /// way to do it) is never clobbered by this system's first run. A present color
+    /// equal to [`ClearColor::default`] is taken to be Bevy's own default and is still
+    /// replaced by the color-scheme color.
+    pub(crate) fn seeded_from(world: &World) -> Self {
+        let current = world.get_resource::<ClearColor>().map(|c| c.0);
+        ManagedClearColor {
+            last_set: current,
+            overridden_by_app: current.is_some_and(|c| c != ClearColor::default().0),
+        }
+    }
+
+    /// Compares `current` against the last color we set, latching
+    /// `overridden_by_app` the first time they diverge, and returns whether the app
+    /// now owns [`ClearColor`] (in which case it should be left alone).
+    fn observe(&mut self, current: Color) -> bool {
+        if !self.overridden_by_app && self.last_set.is_some_and(|ours| ours != current) {
+            self.overridden_by_app = true;
+        }
+        self.overridden_by_app
+    }
+}
+
+/// Applies [`Preferences::reduced_transparency`] and [`Preferences::color_scheme`] to
+/// the primary window every frame, honoring a user-supplied [`WindowAppearance`].
+pub(crate) fn apply_window_appearance(
+    preferences: Res<Preferences>,
+    default_appearance: Option<Res<WindowAppearance>>,
+    mut clear_color: ResMut<ClearColor>,
+    mut managed_clear_color: ResMut<ManagedClearColor>,
+    mut windows: Query<(&mut Window, Option<&WindowAppearance>), With<PrimaryWindow>>,
+) {
+    let Ok((mut window, appearance)) = windows.single_mut() else {
+        return;
+    };
+    let appearance = appearance
+        .copied()
+        .or(default_appearance.as_deref().copied())
+        .unwrap_or_default();
+
+    let opaque = reduced_transparency(&preferences) || appearance == WindowAppearance::Opaque;
+
+    window.transparent = !opaque;
+    window.composite_alpha_mode = if opaque {
+        CompositeAlphaMode::Opaque
+    } else {
+        CompositeAlphaMode::PreMultiplied
+    };
+
+    let app_owns_clear_color = managed_clear_color.observe(clear_color.0);
+    if !app_owns_clear_color {
+        clear_color.0 = scheme_clear_color(&preferences);
+    }
+    if opaque {
+        clear_color.0 = clear_color.0.with_alpha(1.0);
+    }
+    managed_clear_color.last_set = Some(clear_color.0);
+}
+
+/// Whether [`Preferences::reduced_transparency`](crate::Preferences::reduced_transparency)
+/// is set to [`ReducedTransparency::Reduce`]. Falls back to `false` when the
+/// `reduced-transparency` feature is disabled.
+fn reduced_transparency(preferences: &Preferences) -> bool {
+    #[cfg(feature = "reduced-transparency")]
+    {
+        preferences.reduced_transparency == ReducedTransparency::Reduce
+    }
+    #[cfg(not(feature = "reduced-transparency"))]
+    {
+        let _ = preferences;
+        false
+    }
+}
+
+/// The clear color matching [`Preferences::color_scheme`](crate::Preferences::color_scheme).
+/// Falls back to [`LIGHT_CLEAR_COLOR`] when the `color-scheme` feature is disabled.
+fn scheme_clear_color(preferences: &Preferences) -> Color {
+    #[cfg(feature = "color-scheme")]
+    {
+        match preferences.color_scheme {
+            ColorScheme::Dark => DARK_CLEAR_COLOR,
+            _ => LIGHT_CLEAR_COLOR,
+        }
+    }
+    #[cfg(not(feature = "color-scheme"))]
+    {
+        let _ = preferences;
+        LIGHT_CLEAR_COLOR
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_from_world_picks_up_an_app_set_clear_color() {
+        let mut world = World::new();
+        world.insert_resource(ClearColor(DARK_CLEAR_COLOR));
+
+        let managed = ManagedClearColor::seeded_from(&world);
+
+        assert_eq!(managed.last_set, Some(DARK_CLEAR_COLOR));
+        assert!(managed.overridden_by_app);
+    }
+
+    #[test]
+    fn seeded_from_world_without_a_clear_color_has_nothing_to_protect() {
+        let world = World::new();
+
+        let managed = ManagedClearColor::seeded_from(&world);
+
+        assert_eq!(managed.last_set, None);
+    }
+
+    #[test]
+    fn observe_does_not_flag_our_own_write() {
+        let mut managed = ManagedClearColor {
+            last_set: Some(LIGHT_CLEAR_COLOR),
+            overridden_by_app: false,
+        };
+
+        assert!(!managed.observe(LIGHT_CLEAR_COLOR));
+    }
+
+    #[test]
+    fn observe_flags_and_latches_an_external_change() {
+        let mut managed = ManagedClearColor {
+            last_set: Some(LIGHT_CLEAR_COLOR),
+            overridden_by_app: false,
+        };
+
+        assert!(managed.observe(DARK_CLEAR_COLOR));
+
+        // Stays latched even once `last_set` catches up to the app's value, rather
+        // than flipping back to "unowned" on the next frame.
+        managed.last_set = Some(DARK_CLEAR_COLOR);
+        assert!(managed.observe(DARK_CLEAR_COLOR));
+    }
+
+    #[test]
+    fn observe_seeded_from_an_app_set_color_protects_it_on_first_run() {
+        // Regression test: a fresh `ManagedClearColor::default()` (last_set == None)
+        // must not be used on the first system run, or an app's startup-time
+        // `ClearColor` would be silently overwritten.
+        let mut world = World::new();
+        world.insert_resource(ClearColor(DARK_CLEAR_COLOR));
+        let mut managed = ManagedClearColor::seeded_from(&world);
+
+        assert!(managed.observe(DARK_CLEAR_COLOR));
+    }
+}